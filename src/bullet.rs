@@ -0,0 +1,82 @@
+use crate::{
+    map::Map,
+    tiles::{Tile, TextureHandle, Sprite, SpriteLayer},
+    utils::vec2d::{Dot, Point2, Vec2, iPoint2}
+};
+use crate::enemy::Enemy;
+
+const BULLET_HIT_RADIUS_SQ: f64 = 0.16;
+
+pub struct Bullet {
+    pub location: Point2,
+    pub dir: Vec2,
+    pub speed: f64,
+    pub damage: i32,
+    pub life: u16,
+    pub alive: bool,
+    pub textureHandle: TextureHandle
+}
+
+impl Bullet {
+    pub fn New(location: Point2, dir: Vec2, speed: f64, damage: i32, life: u16, textureHandle: TextureHandle) -> Self {
+        Self { location, dir, speed, damage, life, alive: true, textureHandle }
+    }
+
+    fn AsSprite(&self) -> Sprite {
+        Sprite::New(self.location, self.textureHandle, SpriteLayer::Normal)
+    }
+}
+
+pub struct BulletManager {
+    bullets: Vec<Bullet>
+}
+
+impl BulletManager {
+    pub fn New() -> Self {
+        Self { bullets: Vec::new() }
+    }
+
+    pub fn Fire(&mut self, location: Point2, dir: Vec2, speed: f64, damage: i32, life: u16, textureHandle: TextureHandle) {
+        self.bullets.push(Bullet::New(location, dir, speed, damage, life, textureHandle));
+    }
+
+    pub fn Update(&mut self, map: &Map, enemies: &mut Vec<Enemy>) {
+        for bullet in &mut self.bullets {
+            if !bullet.alive {
+                continue;
+            }
+
+            bullet.location = bullet.location + bullet.dir * bullet.speed;
+
+            let hitTile: iPoint2 = bullet.location.into();
+            if !map.WithinMap(hitTile) {
+                bullet.alive = false;
+            } else {
+                match map.GetTile(hitTile) {
+                    Tile::WALL(_) => bullet.alive = false,
+                    Tile::DOOR(door) => if !door.IsOpen() { bullet.alive = false; },
+                    _ => {}
+                }
+            }
+
+            if bullet.alive {
+                for enemy in enemies.iter_mut() {
+                    let toEnemy = enemy.location - bullet.location;
+                    if Dot(toEnemy, toEnemy) <= BULLET_HIT_RADIUS_SQ {
+                        enemy.TakeDamage(bullet.damage);
+                        bullet.alive = false;
+                        break;
+                    }
+                }
+            }
+
+            bullet.life = bullet.life.saturating_sub(1);
+        }
+
+        self.bullets.retain(|b| b.alive && b.life > 0);
+    }
+
+    pub fn GetSprites(&self) -> Vec<Sprite> {
+        self.bullets.iter().map(Bullet::AsSprite).collect()
+    }
+}
@@ -0,0 +1,80 @@
+use sdl2::{pixels::Color, rect::Rect, render::{BlendMode, WindowCanvas}};
+
+const FADE_RATE: f64 = 1.0;
+const DAMAGE_FLASH_DECAY: f64 = 4.0;
+const DAMAGE_FLASH_MAX_ALPHA: u8 = 150;
+
+pub enum FadeState {
+    FadeIn,
+    FadeOut,
+    Hidden,
+    Visible
+}
+
+pub struct Effects {
+    fadeState: FadeState,
+    fadeProgress: f64,
+    damageFlashAlpha: f64
+}
+
+impl Effects {
+    pub fn New() -> Self {
+        Self { fadeState: FadeState::Hidden, fadeProgress: 0.0, damageFlashAlpha: 0.0 }
+    }
+
+    pub fn StartFadeIn(&mut self) {
+        self.fadeState = FadeState::FadeIn;
+        self.fadeProgress = 1.0;
+    }
+
+    pub fn StartFadeOut(&mut self) {
+        self.fadeState = FadeState::FadeOut;
+        self.fadeProgress = 0.0;
+    }
+
+    pub fn TriggerDamageFlash(&mut self) {
+        self.damageFlashAlpha = 1.0;
+    }
+
+    pub fn Update(&mut self, dt: f64) {
+        match self.fadeState {
+            FadeState::FadeIn => {
+                self.fadeProgress -= FADE_RATE * dt;
+                if self.fadeProgress <= 0.0 {
+                    self.fadeProgress = 0.0;
+                    self.fadeState = FadeState::Hidden;
+                }
+            },
+            FadeState::FadeOut => {
+                self.fadeProgress += FADE_RATE * dt;
+                if self.fadeProgress >= 1.0 {
+                    self.fadeProgress = 1.0;
+                    self.fadeState = FadeState::Visible;
+                }
+            },
+            FadeState::Hidden | FadeState::Visible => {}
+        }
+
+        if self.damageFlashAlpha > 0.0 {
+            self.damageFlashAlpha = (self.damageFlashAlpha - DAMAGE_FLASH_DECAY * dt).max(0.0);
+        }
+    }
+
+    pub fn Draw(&self, sdlCanvas: &mut WindowCanvas, width: u32, height: u32) {
+        sdlCanvas.set_blend_mode(BlendMode::Blend);
+
+        if self.fadeProgress > 0.0 {
+            let alpha = (self.fadeProgress * 255.0) as u8;
+            sdlCanvas.set_draw_color(Color::RGBA(0, 0, 0, alpha));
+            let _ = sdlCanvas.fill_rect(Rect::new(0, 0, width, height));
+        }
+
+        if self.damageFlashAlpha > 0.0 {
+            let alpha = (self.damageFlashAlpha * DAMAGE_FLASH_MAX_ALPHA as f64) as u8;
+            sdlCanvas.set_draw_color(Color::RGBA(200, 0, 0, alpha));
+            let _ = sdlCanvas.fill_rect(Rect::new(0, 0, width, height));
+        }
+
+        sdlCanvas.set_blend_mode(BlendMode::None);
+    }
+}
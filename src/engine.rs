@@ -1,6 +1,7 @@
 use core::panic;
+use std::time::Instant;
 
-use sdl2::{pixels::Color, rect::Rect};
+use sdl2::{pixels::{Color, PixelFormatEnum}, rect::{Point, Rect}, render::{BlendMode, Texture}, video::WindowContext};
 use crate::{
     multimedia::{Multimedia, LightTexture, TextureType},
     inputs_buffer::InputsBuffer,
@@ -9,11 +10,30 @@ use crate::{
     utils::{
         ray::Ray,
         dda::RayCursor, vec2d::{Dot, Vec2, Point2, iPoint2}, conventions::TEXTURE_PITCH
-    }, tiles::{Tile, TextureHandle, Sprite, WallSlice}
+    }, tiles::{Tile, TextureHandle, Sprite, SpriteLayer, WallSlice}
 };
 use crate::enemy::Enemy;
+use crate::bullet::BulletManager;
+use crate::hud::{Hud, BAR_HEIGHT};
+use crate::effects::Effects;
 use crate::utils::vec2d::iVec2;
 
+const BULLET_DAMAGE: i32 = 10;
+const BULLET_LIFE: u16 = 120;
+const BULLET_TEXTURE_ID: u32 = 50;
+
+// Simulation always steps at a constant rate; only rendering interpolates between steps at whatever rate the display refreshes.
+const FIXED_DT: f64 = 1.0 / 60.0;
+const MAX_ACCUMULATED_DT: f64 = FIXED_DT * 5.0;
+
+fn LerpPoint(a: Point2, b: Point2, alpha: f64) -> Point2 {
+    Point2::New(a.x() + (b.x() - a.x()) * alpha, a.y() + (b.y() - a.y()) * alpha)
+}
+
+fn LerpDir(a: Vec2, b: Vec2, alpha: f64) -> Vec2 {
+    Vec2::New(a.x() + (b.x() - a.x()) * alpha, a.y() + (b.y() - a.y()) * alpha).Normalize()
+}
+
 struct SpriteRenderData {
     vecToSprite: Vec2,
     spriteHitDistY: f64,
@@ -21,7 +41,16 @@ struct SpriteRenderData {
     spriteScreenX: i32,
     spriteRenderHeight: i32,
     spriteScreenRect: Rect,
-    spriteTextureHandle: TextureHandle
+    spriteTextureHandle: TextureHandle,
+    spriteLayer: SpriteLayer
+}
+
+fn SpriteLayerOrdinal(layer: SpriteLayer) -> u8 {
+    match layer {
+        SpriteLayer::Background => 0,
+        SpriteLayer::Normal => 1,
+        SpriteLayer::Foreground => 2
+    }
 }
 
 pub struct GameEngine {
@@ -35,18 +64,52 @@ pub struct GameEngine {
     doorTimerIncr: f64,
     playerMoveIncr: f64,
     playerSwivelIncr: f64,
+    bulletSpeedIncr: f64,
+
+    // Interpolation related
+    prevPlayerLocation: Point2,
+    currPlayerLocation: Point2,
+    prevViewDir: Vec2,
+    currViewDir: Vec2,
+    renderPlayerLocation: Point2,
+    renderViewDir: Vec2,
+    renderEast: Vec2,
+    renderAlpha: f64,
 
     // Render related
     wallSlicesBuffer: Vec<WallSlice>,
+    wallSliceIsDoorBuffer: Vec<bool>,
+    wallSliceDoorOpenBuffer: Vec<bool>,
     spritesBuffer: Vec<Sprite>,
     spritesRenderDataBuffer: Vec<SpriteRenderData>,
     wallRenderHeights: Vec<i32>,
+    wallIsDoor: Vec<bool>,
+    wallDoorOpen: Vec<bool>,
     spriteTileHitMap: Vec<Vec<bool>>,
     weaponRenderTopLeft: iVec2,
     weaponRenderPitch: i32,
+    floorCeilingTexture: Texture<'static>,
 
     // Enemy related
-    enemies: Vec<Enemy>
+    enemies: Vec<Enemy>,
+
+    // Bullet related
+    bulletManager: BulletManager,
+
+    // HUD related
+    hud: Hud,
+    viewportHeight: usize,
+
+    // Effects related
+    effects: Effects,
+    lastHealth: i32,
+
+    // Minimap related
+    minimapVisible: bool,
+    minimapCellSize: i32,
+    minimapRadius: i32,
+    minimapFullScreenCellSize: i32,
+    minimapFullScreenRadius: i32
 }
 
 impl GameEngine {
@@ -56,13 +119,16 @@ impl GameEngine {
         let player = Player::New(Point2::New(22.5, 2.5), multimedia.displayParams.refreshRate);
         let (map, enemies): (Map, Vec<Enemy>) = Map::LoadFromCSV(mapCSVPath, multimedia.displayParams.refreshRate);
         
-        let refreshRatePropr = multimedia.displayParams.refreshRate as f64 / 60.0;
-        let doorMoveIncr = 0.02/refreshRatePropr;
-        let doorTimerIncr = 0.01/refreshRatePropr;
-        let playerMoveIncr = 0.08/refreshRatePropr;
-        let playerSwivelIncr = 0.00125/refreshRatePropr;
+        // These are expressed per fixed 60Hz simulation tick, so they no longer need scaling by the display's refresh rate.
+        let doorMoveIncr = 0.02;
+        let doorTimerIncr = 0.01;
+        let playerMoveIncr = 0.08;
+        let playerSwivelIncr = 0.00125;
+        let bulletSpeedIncr = 0.3;
 
         let wallRenderHeights: Vec<i32> = vec![0; multimedia.windowParams.width];
+        let wallIsDoor: Vec<bool> = vec![false; multimedia.windowParams.width];
+        let wallDoorOpen: Vec<bool> = vec![false; multimedia.windowParams.width];
 
         let spriteTileHitMap: Vec<Vec<bool>> = vec![vec![false; map.height as usize]; map.width as usize];
 
@@ -70,6 +136,23 @@ impl GameEngine {
         let weaponRenderX = (windowWidth/2) - (weaponRenderPitch/2) as usize;
         let weaponRenderY = windowHeight - weaponRenderPitch as usize;
 
+        let hud = Hud::New(windowWidth, windowHeight);
+        let viewportHeight = windowHeight - BAR_HEIGHT as usize;
+
+        // Leaked once at startup so the streaming texture below can live on GameEngine for its
+        // whole lifetime instead of being recreated (and the old one dropped) every frame.
+        let textureCreator: &'static sdl2::render::TextureCreator<WindowContext> = Box::leak(Box::new(multimedia.sdlCanvas.texture_creator()));
+        let floorCeilingTexture = textureCreator
+            .create_texture_streaming(PixelFormatEnum::RGBA32, windowWidth as u32, viewportHeight as u32)
+            .expect("failed to create floor/ceiling streaming texture");
+
+        let mut effects = Effects::New();
+        effects.StartFadeIn();
+        let lastHealth = player.health;
+
+        let playerLocation = player.location;
+        let viewDir = player.viewDir;
+
         Self {
             multimedia,
             inputsBuffer,
@@ -80,62 +163,212 @@ impl GameEngine {
             doorTimerIncr,
             playerMoveIncr,
             playerSwivelIncr,
+            bulletSpeedIncr,
+
+            prevPlayerLocation: playerLocation,
+            currPlayerLocation: playerLocation,
+            prevViewDir: viewDir,
+            currViewDir: viewDir,
+            renderPlayerLocation: playerLocation,
+            renderViewDir: viewDir,
+            renderEast: viewDir.Rotate(std::f64::consts::FRAC_PI_2),
+            renderAlpha: 0.0,
 
             wallSlicesBuffer: Vec::new(),
+            wallSliceIsDoorBuffer: Vec::new(),
+            wallSliceDoorOpenBuffer: Vec::new(),
             spritesBuffer: Vec::new(),
             spritesRenderDataBuffer: Vec::new(),
             wallRenderHeights,
+            wallIsDoor,
+            wallDoorOpen,
 
             spriteTileHitMap,
 
             weaponRenderTopLeft: iVec2::New(weaponRenderX as i32, weaponRenderY as i32),
             weaponRenderPitch,
+            floorCeilingTexture,
+
+            enemies,
 
-            enemies
+            bulletManager: BulletManager::New(),
+
+            hud,
+            viewportHeight,
+
+            effects,
+            lastHealth,
+
+            minimapVisible: false,
+            minimapCellSize: 6,
+            minimapRadius: 10,
+            minimapFullScreenCellSize: 16,
+            minimapFullScreenRadius: 25
         }
     }
 
     pub fn GameLoop(&mut self) {
+        let mut accumulator = 0.0;
+        let mut lastInstant = Instant::now();
+
         loop {
-            self.Update();
+            let now = Instant::now();
+            accumulator += (now - lastInstant).as_secs_f64();
+            lastInstant = now;
+
+            if accumulator > MAX_ACCUMULATED_DT {
+                accumulator = MAX_ACCUMULATED_DT;
+            }
+
+            while accumulator >= FIXED_DT {
+                self.SnapshotPrevState();
+                self.Update();
+                self.currPlayerLocation = self.player.location;
+                self.currViewDir = self.player.viewDir;
+
+                if self.inputsBuffer.quit { break; }
+                accumulator -= FIXED_DT;
+            }
             if self.inputsBuffer.quit { break; }
+
+            self.renderAlpha = accumulator / FIXED_DT;
             self.RenderFrame();
         }
     }
 
+    fn SnapshotPrevState(&mut self) {
+        self.prevPlayerLocation = self.currPlayerLocation;
+        self.prevViewDir = self.currViewDir;
+        self.map.SnapshotDoorState();
+    }
+
     fn Update(&mut self) {
         self.inputsBuffer.Update(&mut self.multimedia.sdlContexts.sdlContext, &mut self.multimedia.sdlEventPump);
         self.UpdateEnemies();
+        self.bulletManager.Update(&self.map, &mut self.enemies);
         self.player.Update(&self.inputsBuffer, &mut self.map, &mut self.enemies, self.playerMoveIncr, self.playerSwivelIncr);
+        if self.inputsBuffer.fire && self.player.ammo > 0 {
+            self.FireBullet();
+        }
         self.map.UpdateDoors(self.doorMoveIncr, self.doorTimerIncr, self.player.location);
+        self.UpdateEffects();
+        if self.inputsBuffer.toggleMinimap {
+            self.minimapVisible = !self.minimapVisible;
+        }
+    }
+
+    fn UpdateEffects(&mut self) {
+        if self.player.health < self.lastHealth {
+            self.effects.TriggerDamageFlash();
+        }
+        if self.player.health <= 0 && self.lastHealth > 0 {
+            self.effects.StartFadeOut();
+        }
+        self.lastHealth = self.player.health;
+
+        self.effects.Update(FIXED_DT);
+    }
+
+    fn FireBullet(&mut self) {
+        let textureHandle = TextureHandle::New(TextureType::OBJECT, BULLET_TEXTURE_ID);
+        self.bulletManager.Fire(self.player.location, self.player.viewDir, self.bulletSpeedIncr, BULLET_DAMAGE, BULLET_LIFE, textureHandle);
+        self.player.ammo -= 1;
     }
 
     fn RenderFrame(&mut self) {
+        self.renderPlayerLocation = LerpPoint(self.prevPlayerLocation, self.currPlayerLocation, self.renderAlpha);
+        self.renderViewDir = LerpDir(self.prevViewDir, self.currViewDir, self.renderAlpha);
+        self.renderEast = self.renderViewDir.Rotate(std::f64::consts::FRAC_PI_2);
+
         self.multimedia.sdlCanvas.clear();
         self.DrawCeilingAndFloor();
         self.RenderIntoBuffers();
         self.DrawWallsFromBuffer();
         self.DrawSpritesFromBuffer();
         self.DrawWeapon();
+        self.DrawMinimap();
+        self.hud.Draw(&mut self.multimedia, &self.player);
+        self.effects.Draw(&mut self.multimedia.sdlCanvas, self.multimedia.windowParams.width as u32, self.multimedia.windowParams.height as u32);
         self.multimedia.sdlCanvas.present();
     }
 
     fn DrawCeilingAndFloor(&mut self) {
-        self.multimedia.sdlCanvas.set_draw_color(Color::RGBA(50, 50, 50, 255));
-        self.multimedia.sdlCanvas.fill_rect(Rect::new(0, 0, self.multimedia.windowParams.width as u32, (self.multimedia.windowParams.height/2) as u32)).unwrap();
+        let width = self.multimedia.windowParams.width;
+        let height = self.viewportHeight;
+        let halfHeight = height as f64 / 2.0;
+
+        let rayDirLeft = self.renderViewDir.Rotate(self.multimedia.renderParams.castingRayAngles[0].0);
+        let rayDirRight = self.renderViewDir.Rotate(self.multimedia.renderParams.castingRayAngles[width - 1].0);
+
+        let defaultFloorTexture = TextureHandle::New(TextureType::FLOOR, 1);
+        let defaultCeilingTexture = TextureHandle::New(TextureType::CEILING, 1);
+
+        let pitch = width * 4;
+        let mut pixels = vec![0u8; pitch * height];
+
+        // A row only has infinite rowDistance when it sits exactly on the horizon, i.e. when
+        // height is even and y == halfHeight; for odd height no integer row does, so every row
+        // is castable and none should be skipped. Ceiling and floor rows are cast independently
+        // (rather than mirroring one onto the other) since there is always one more row above the
+        // horizon than below it (height/2 against height/2-1 for even height, (height+1)/2 against
+        // (height-1)/2 for odd height), and deriving one side from the other's loop index leaves
+        // one row permanently unwritten.
+        for y in 0..height {
+            if y as f64 == halfHeight {
+                continue;
+            }
+            let isFloor = y as f64 > halfHeight;
+            let rowDistance = if isFloor {
+                (halfHeight * self.multimedia.renderParams.projPlaneDist) / (y as f64 - halfHeight)
+            } else {
+                (halfHeight * self.multimedia.renderParams.projPlaneDist) / (halfHeight - y as f64)
+            };
+            let floorStep = (rayDirRight - rayDirLeft) * (rowDistance / width as f64);
+            let mut floor = self.renderPlayerLocation + rayDirLeft * rowDistance;
+
+            for x in 0..width {
+                let tileCoord: iPoint2 = floor.into();
+
+                let (floorTextureHandle, ceilingTextureHandle) = if self.map.WithinMap(tileCoord) {
+                    let tile = self.map.GetTile(tileCoord);
+                    (tile.FloorTexture().unwrap_or(defaultFloorTexture), tile.CeilingTexture().unwrap_or(defaultCeilingTexture))
+                } else {
+                    (defaultFloorTexture, defaultCeilingTexture)
+                };
+
+                let texX = (floor.x().fract().abs() * TEXTURE_PITCH as f64) as i32;
+                let texY = (floor.y().fract().abs() * TEXTURE_PITCH as f64) as i32;
+
+                let color = if isFloor {
+                    self.multimedia.assets.SamplePixel(floorTextureHandle, texX, texY)
+                } else {
+                    self.multimedia.assets.SamplePixel(ceilingTextureHandle, texX, (TEXTURE_PITCH as i32 - 1) - texY)
+                };
+                let offset = (y * pitch) + (x * 4);
+                pixels[offset] = color.r;
+                pixels[offset + 1] = color.g;
+                pixels[offset + 2] = color.b;
+                pixels[offset + 3] = 255;
 
-        self.multimedia.sdlCanvas.set_draw_color(Color::RGBA(96, 96, 96, 255));
-        self.multimedia.sdlCanvas.fill_rect(Rect::new(0, (self.multimedia.windowParams.height / 2) as i32, self.multimedia.windowParams.width as u32, (self.multimedia.windowParams.height/2) as u32)).unwrap();
+                floor = floor + floorStep;
+            }
+        }
+
+        let _ = self.floorCeilingTexture.update(None, &pixels, pitch);
+        let _ = self.multimedia.sdlCanvas.copy(&self.floorCeilingTexture, None, Rect::new(0, 0, width as u32, height as u32));
     }
 
     fn RenderIntoBuffers(&mut self) {
         self.wallSlicesBuffer.clear();
+        self.wallSliceIsDoorBuffer.clear();
+        self.wallSliceDoorOpenBuffer.clear();
         self.spritesBuffer.clear();
         self.ResetSpriteTileHitMap();
+        self.spritesBuffer.extend(self.bulletManager.GetSprites());
 
         for x in 0..self.multimedia.windowParams.width {
-            let currRay = Ray::New(self.player.location, self.player.viewDir.Rotate(self.multimedia.renderParams.castingRayAngles[x].0));
-            let mut rayCursor = RayCursor::New(currRay, self.player.location);
+            let currRay = Ray::New(self.renderPlayerLocation, self.renderViewDir.Rotate(self.multimedia.renderParams.castingRayAngles[x].0));
+            let mut rayCursor = RayCursor::New(currRay, self.renderPlayerLocation);
             let mut prevTileCoord = rayCursor.hitTile;
             while self.map.WithinMap(rayCursor.hitTile) {
                 let prevTileWasDoor = if let Tile::DOOR(_) = self.map.GetTile(prevTileCoord) { true } else { false };
@@ -152,12 +385,16 @@ impl GameEngine {
                             wallSlice.textureHandle = LightTexture(&mut rayCursor, gateSidewall_lit, gateSideWall_unlit);
                         }
                         self.wallSlicesBuffer.push(wallSlice);
+                        self.wallSliceIsDoorBuffer.push(false);
+                        self.wallSliceDoorOpenBuffer.push(false);
                         break;
                     },
                     Tile::DOOR(door) => {
-                        let doorWallSlice = door.GetWallSlice(&mut rayCursor);
+                        let doorWallSlice = door.GetWallSlice(&mut rayCursor, self.renderAlpha);
                         if doorWallSlice.is_some() {
                             self.wallSlicesBuffer.push(doorWallSlice.unwrap());
+                            self.wallSliceIsDoorBuffer.push(true);
+                            self.wallSliceDoorOpenBuffer.push(door.IsOpen());
                             break;
                         } else {
                             continue;
@@ -179,9 +416,11 @@ impl GameEngine {
 
             let distToHitPoint = wallSlice.dist;
             let renderHeight = (self.multimedia.renderParams.renderHeightProprConst / (distToHitPoint * self.multimedia.renderParams.castingRayAngles[x as usize].1)) as i32;
-            let screenY = (self.multimedia.windowParams.height/2) as i32 - (renderHeight / 2);
+            let screenY = (self.viewportHeight/2) as i32 - (renderHeight / 2);
             let screenRect = Rect::new(x as i32, screenY, 1, renderHeight as u32);
             self.wallRenderHeights[x as usize] = renderHeight;
+            self.wallIsDoor[x as usize] = self.wallSliceIsDoorBuffer[x];
+            self.wallDoorOpen[x as usize] = self.wallSliceDoorOpenBuffer[x];
 
             let texture = self.multimedia.assets.GetTexture(wallSlice.textureHandle);
 
@@ -192,14 +431,15 @@ impl GameEngine {
 
     fn DrawSpritesFromBuffer(&mut self) {
         self.spritesRenderDataBuffer.clear();
-        for sprite in &self.spritesBuffer {            
-            let vecToSprite = sprite.location - self.player.location;
-            let spriteHitDistY = Dot(vecToSprite, self.player.viewDir);
-            let spriteHitDistX = Dot(vecToSprite, self.player.east);
+        for sprite in &self.spritesBuffer {
+            let vecToSprite = sprite.location - self.renderPlayerLocation;
+            let spriteHitDistY = Dot(vecToSprite, self.renderViewDir);
+            let spriteHitDistX = Dot(vecToSprite, self.renderEast);
             let spriteScreenX = ((self.multimedia.windowParams.width/2) as f64 + ((self.multimedia.renderParams.projPlaneDist/spriteHitDistY)*spriteHitDistX)) as i32;
             let spriteRenderHeight = (self.multimedia.renderParams.renderHeightProprConst / spriteHitDistY) as i32;
-            let spriteScreenRect = Rect::new(spriteScreenX - (spriteRenderHeight/2), (self.multimedia.windowParams.height as i32)/2 - (spriteRenderHeight/2), spriteRenderHeight as u32, spriteRenderHeight as u32);
+            let spriteScreenRect = Rect::new(spriteScreenX - (spriteRenderHeight/2), (self.viewportHeight as i32)/2 - (spriteRenderHeight/2), spriteRenderHeight as u32, spriteRenderHeight as u32);
             let spriteTextureHandle = sprite.textureHandle;
+            let spriteLayer = sprite.layer;
 
             self.spritesRenderDataBuffer.push(SpriteRenderData {
                 vecToSprite,
@@ -208,11 +448,15 @@ impl GameEngine {
                 spriteScreenX,
                 spriteRenderHeight,
                 spriteScreenRect,
-                spriteTextureHandle
+                spriteTextureHandle,
+                spriteLayer
             });
         }
 
-        self.spritesRenderDataBuffer.sort_by(|a, b| a.spriteRenderHeight.partial_cmp(&b.spriteRenderHeight).unwrap());
+        self.spritesRenderDataBuffer.sort_by(|a, b| {
+            SpriteLayerOrdinal(a.spriteLayer).cmp(&SpriteLayerOrdinal(b.spriteLayer))
+                .then(a.spriteRenderHeight.partial_cmp(&b.spriteRenderHeight).unwrap())
+        });
 
         for s in &self.spritesRenderDataBuffer {
             for x in s.spriteScreenRect.x..(s.spriteScreenRect.x+s.spriteScreenRect.w) {
@@ -221,12 +465,21 @@ impl GameEngine {
                 } else if x >= self.multimedia.windowParams.width as i32 {
                     break;
                 } else {
-                    if self.wallRenderHeights[x as usize] <= s.spriteRenderHeight {
+                    // Background sprites represent backdrop seen through doorways: only a door that
+                    // is actually open lets them bypass the depth test, matching BulletManager's
+                    // rule that a closed door is solid.
+                    let visible = match s.spriteLayer {
+                        SpriteLayer::Foreground => true,
+                        SpriteLayer::Normal => self.wallRenderHeights[x as usize] <= s.spriteRenderHeight,
+                        SpriteLayer::Background => (self.wallIsDoor[x as usize] && self.wallDoorOpen[x as usize]) || self.wallRenderHeights[x as usize] <= s.spriteRenderHeight
+                    };
+
+                    if visible {
                         let spriteTextureWidthPercent = (x - s.spriteScreenRect.x) as f64 / (s.spriteScreenRect.w) as f64;
                         let spriteTextureX = (spriteTextureWidthPercent * TEXTURE_PITCH as f64) as i32;
                         let spriteTextureRect = Rect::new(spriteTextureX, 0, 1, TEXTURE_PITCH);
                         let screenRect = Rect::new(x, s.spriteScreenRect.y, 1, s.spriteScreenRect.h as u32);
-                        
+
                         let texture = self.multimedia.assets.GetTexture(s.spriteTextureHandle);
 
                         let _ = self.multimedia.sdlCanvas.copy(texture, spriteTextureRect, screenRect);
@@ -244,6 +497,71 @@ impl GameEngine {
         let _ = self.multimedia.sdlCanvas.copy(texture, textureRect, screenRect);
     }
 
+    fn DrawMinimap(&mut self) {
+        if !self.minimapVisible {
+            return;
+        }
+
+        // Floor cells are drawn with a semi-transparent fill, so blending has to be on for it
+        // to actually show through instead of painting fully opaque (same pattern as Effects::Draw).
+        self.multimedia.sdlCanvas.set_blend_mode(BlendMode::Blend);
+
+        let fullScreen = self.inputsBuffer.minimapFullScreen;
+        let cellSize = if fullScreen { self.minimapFullScreenCellSize } else { self.minimapCellSize };
+        let radius = if fullScreen { self.minimapFullScreenRadius } else { self.minimapRadius };
+
+        let originX = if fullScreen { (self.multimedia.windowParams.width as i32)/2 - radius*cellSize } else { 10 };
+        let originY = if fullScreen { (self.viewportHeight as i32)/2 - radius*cellSize } else { 10 };
+
+        let playerTile: iPoint2 = self.renderPlayerLocation.into();
+
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                let tileCoord = iPoint2::New(playerTile.x() + dx, playerTile.y() + dy);
+                if !self.map.WithinMap(tileCoord) {
+                    continue;
+                }
+
+                let color = match self.map.GetTile(tileCoord) {
+                    Tile::WALL(_) => Color::RGBA(150, 150, 150, 255),
+                    Tile::DOOR(door) => if door.IsOpen() { Color::RGBA(120, 90, 40, 255) } else { Color::RGBA(180, 120, 50, 255) },
+                    _ => Color::RGBA(40, 40, 40, 180)
+                };
+
+                let screenX = originX + (dx + radius) * cellSize;
+                let screenY = originY + (dy + radius) * cellSize;
+
+                self.multimedia.sdlCanvas.set_draw_color(color);
+                let _ = self.multimedia.sdlCanvas.fill_rect(Rect::new(screenX, screenY, cellSize as u32, cellSize as u32));
+            }
+        }
+
+        for enemy in &self.enemies {
+            let enemyTile: iPoint2 = enemy.location.into();
+            let dx = enemyTile.x() - playerTile.x();
+            let dy = enemyTile.y() - playerTile.y();
+            if dx.abs() <= radius && dy.abs() <= radius {
+                let screenX = originX + (dx + radius) * cellSize;
+                let screenY = originY + (dy + radius) * cellSize;
+
+                self.multimedia.sdlCanvas.set_draw_color(Color::RGBA(200, 30, 30, 255));
+                let _ = self.multimedia.sdlCanvas.fill_rect(Rect::new(screenX, screenY, cellSize as u32, cellSize as u32));
+            }
+        }
+
+        let playerScreenX = originX + radius*cellSize + cellSize/2;
+        let playerScreenY = originY + radius*cellSize + cellSize/2;
+        let arrowTip = self.renderViewDir * (cellSize as f64 * 1.5);
+
+        self.multimedia.sdlCanvas.set_draw_color(Color::RGBA(255, 255, 0, 255));
+        let _ = self.multimedia.sdlCanvas.draw_line(
+            Point::new(playerScreenX, playerScreenY),
+            Point::new(playerScreenX + arrowTip.x() as i32, playerScreenY + arrowTip.y() as i32)
+        );
+
+        self.multimedia.sdlCanvas.set_blend_mode(BlendMode::None);
+    }
+
     fn ResetSpriteTileHitMap(&mut self) {
         for x in 0..self.map.width {
             for y in 0..self.map.height {
@@ -255,6 +573,8 @@ impl GameEngine {
     fn ResetWallRenderHeights(&mut self) {
         for i in 0..self.wallRenderHeights.len() {
             self.wallRenderHeights[i] = 0;
+            self.wallIsDoor[i] = false;
+            self.wallDoorOpen[i] = false;
         }
     }
 
@@ -0,0 +1,68 @@
+use sdl2::rect::Rect;
+use crate::{
+    multimedia::{Multimedia, TextureType},
+    player::Player,
+    tiles::TextureHandle,
+    utils::conventions::TEXTURE_PITCH
+};
+
+pub const BAR_HEIGHT: i32 = 40;
+
+const DIGIT_PITCH: i32 = 16;
+
+pub struct Hud {
+    barTopLeft: (i32, i32),
+    barWidth: i32
+}
+
+impl Hud {
+    pub fn New(windowWidth: usize, windowHeight: usize) -> Self {
+        Self {
+            barTopLeft: (0, (windowHeight as i32) - BAR_HEIGHT),
+            barWidth: windowWidth as i32
+        }
+    }
+
+    pub fn Draw(&self, multimedia: &mut Multimedia, player: &Player) {
+        let barRect = Rect::new(self.barTopLeft.0, self.barTopLeft.1, self.barWidth as u32, BAR_HEIGHT as u32);
+        let barTexture = multimedia.assets.GetTexture(TextureHandle::New(TextureType::HUD, 0));
+        let _ = multimedia.sdlCanvas.copy(barTexture, Rect::new(0, 0, TEXTURE_PITCH, TEXTURE_PITCH), barRect);
+
+        self.DrawNumber(multimedia, player.health, self.barTopLeft.0 + 160, TextureHandle::New(TextureType::HUD, 1));
+        self.DrawNumber(multimedia, player.ammo, self.barTopLeft.0 + 280, TextureHandle::New(TextureType::HUD, 1));
+        self.DrawNumber(multimedia, player.lives, self.barTopLeft.0 + 360, TextureHandle::New(TextureType::HUD, 1));
+        self.DrawNumber(multimedia, player.score, self.barWidth - 20, TextureHandle::New(TextureType::HUD, 1));
+
+        let weaponIconRect = Rect::new(self.barWidth/2 - (BAR_HEIGHT/2), self.barTopLeft.1 + 4, (BAR_HEIGHT - 8) as u32, (BAR_HEIGHT - 8) as u32);
+        let weaponIconTexture = multimedia.assets.GetTexture(player.AM_weapon.GetCurrTexture());
+        let _ = multimedia.sdlCanvas.copy(weaponIconTexture, Rect::new(0, 0, TEXTURE_PITCH, TEXTURE_PITCH), weaponIconRect);
+
+        let faceRect = Rect::new(self.barTopLeft.0 + 420, self.barTopLeft.1 + 2, (BAR_HEIGHT - 4) as u32, (BAR_HEIGHT - 4) as u32);
+        let faceTexture = multimedia.assets.GetTexture(self.FaceTextureForHealth(player.health));
+        let _ = multimedia.sdlCanvas.copy(faceTexture, Rect::new(0, 0, TEXTURE_PITCH, TEXTURE_PITCH), faceRect);
+    }
+
+    fn DrawNumber(&self, multimedia: &mut Multimedia, value: i32, rightAlignedAt: i32, digitsTextureHandle: TextureHandle) {
+        let digitsTexture = multimedia.assets.GetTexture(digitsTextureHandle);
+        let mut remaining = value.max(0);
+        let mut x = rightAlignedAt;
+
+        loop {
+            let digit = remaining % 10;
+            x -= DIGIT_PITCH;
+            let srcRect = Rect::new(digit * DIGIT_PITCH, 0, DIGIT_PITCH as u32, DIGIT_PITCH as u32);
+            let dstRect = Rect::new(x, self.barTopLeft.1 + (BAR_HEIGHT - DIGIT_PITCH)/2, DIGIT_PITCH as u32, DIGIT_PITCH as u32);
+            let _ = multimedia.sdlCanvas.copy(digitsTexture, srcRect, dstRect);
+
+            remaining /= 10;
+            if remaining == 0 {
+                break;
+            }
+        }
+    }
+
+    fn FaceTextureForHealth(&self, health: i32) -> TextureHandle {
+        let bucket = if health > 75 { 0 } else if health > 50 { 1 } else if health > 25 { 2 } else if health > 0 { 3 } else { 4 };
+        TextureHandle::New(TextureType::HUD, 10 + bucket)
+    }
+}